@@ -0,0 +1,93 @@
+//! Backend API surface exposed to the StorePulse frontend via Tauri's
+//! `invoke_handler`.
+//!
+//! Every command returns a [`CommandResult`] so the frontend receives
+//! `Result`-shaped responses it can pattern match on. New commands can be added
+//! here and appended to the `generate_handler!` list in `run` (in `lib.rs`)
+//! without touching any other wiring.
+
+use serde::Serialize;
+
+/// Errors surfaced to the frontend from a command.
+///
+/// Serialized adjacently tagged, as `{ "kind": <variant>, "message": <text> }`,
+/// so the frontend can branch on the variant rather than parsing a message
+/// string.
+#[derive(Debug, Serialize, thiserror::Error)]
+#[serde(tag = "kind", content = "message")]
+pub enum CommandError {
+    /// The requested store or location could not be found.
+    #[error("not found: {0}")]
+    NotFound(String),
+    /// A backing data source (DB, metrics service) was unavailable.
+    #[error("data source unavailable: {0}")]
+    DataSource(String),
+}
+
+/// Convenience alias for command return types.
+pub type CommandResult<T> = Result<T, CommandError>;
+
+/// Aggregate metrics for a single store location.
+#[derive(Debug, Serialize)]
+pub struct StoreMetrics {
+    pub location_id: String,
+    pub revenue_today: f64,
+    pub transactions_today: u32,
+    pub footfall: u32,
+}
+
+/// A store location the operator can drill into.
+#[derive(Debug, Serialize)]
+pub struct Location {
+    pub id: String,
+    pub name: String,
+    pub region: String,
+}
+
+/// A single entry in the live alert feed.
+#[derive(Debug, Serialize)]
+pub struct Alert {
+    pub id: String,
+    pub location_id: String,
+    pub severity: String,
+    pub message: String,
+}
+
+/// Metrics for a store location.
+///
+/// No data source is connected: this returns zeroed metrics for `location_id`.
+#[tauri::command]
+pub async fn fetch_store_metrics(location_id: String) -> CommandResult<StoreMetrics> {
+    Ok(StoreMetrics {
+        location_id,
+        revenue_today: 0.0,
+        transactions_today: 0,
+        footfall: 0,
+    })
+}
+
+/// Store locations available to the current operator.
+///
+/// No data source is connected: this always returns an empty list.
+#[tauri::command]
+pub async fn list_locations() -> CommandResult<Vec<Location>> {
+    Ok(Vec::new())
+}
+
+/// Inventory item count for a location.
+///
+/// No inventory sync is performed: `location_id` is ignored and this always
+/// returns `0`.
+#[tauri::command]
+pub async fn refresh_inventory(location_id: String) -> CommandResult<u32> {
+    let _ = location_id;
+    Ok(0)
+}
+
+/// Most recent alerts across all locations.
+///
+/// No alert source is connected: this always returns an empty list.
+#[tauri::command]
+pub async fn get_alert_feed() -> CommandResult<Vec<Alert>> {
+    Ok(Vec::new())
+}
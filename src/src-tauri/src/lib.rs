@@ -0,0 +1,98 @@
+use tauri::{Emitter, Manager, Wry};
+#[cfg(desktop)]
+use tauri_plugin_window_state::{StateFlags, WindowExt};
+
+mod commands;
+mod window;
+
+/// Warm up the backend before the main window is revealed.
+///
+/// Runs on the async runtime, off the UI thread. An `Err` carries a message for
+/// the splashscreen. No DB connection, metrics fetch or config validation is
+/// performed: this returns `Ok(())` immediately, so the error path is never
+/// taken.
+async fn warm_up(_app: tauri::AppHandle<Wry>) -> Result<(), String> {
+    // Warm up DB connections, fetch initial store metrics and validate config.
+    // These subsystems are stubbed until they land; the point is that the work
+    // runs off the UI thread so the splashscreen stays responsive meanwhile.
+    Ok(())
+}
+
+/// Build and run the StorePulse application.
+///
+/// Shared by the desktop `main` entry point and the mobile entry point so both
+/// targets drive the same setup and command wiring from one code path.
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    let builder = tauri::Builder::<Wry>::default();
+
+    // The window-state and single-instance plugins only exist on desktop; the
+    // mobile entry point skips them.
+    #[cfg(desktop)]
+    let builder = builder
+        // single-instance must be the first plugin registered so it can bail
+        // out of a duplicate launch before any other setup runs.
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            // A second launch just focuses the window that is already running,
+            // which matters for a kiosk/back-office tool left up all day.
+            if let Some(main_window) = app.get_webview_window("main") {
+                let _ = main_window.unminimize();
+                let _ = main_window.set_focus();
+            }
+        }))
+        // Strip VISIBLE from the saved flags: the plugin's own window-ready
+        // handler auto-restores every window with all flags, and with VISIBLE
+        // set that shows the `main` window (configured `visible:false`) before
+        // `warm_up` resolves — defeating the splashscreen gate. The plugin
+        // restores every flag except VISIBLE; the manual `restore_state` in
+        // `setup` restores size, position and maximized state only.
+        .plugin(
+            tauri_plugin_window_state::Builder::default()
+                .with_state_flags(StateFlags::all() & !StateFlags::VISIBLE)
+                .build(),
+        );
+
+    builder
+        .manage(window::WindowRegistry::default())
+        .invoke_handler(tauri::generate_handler![
+            commands::fetch_store_metrics,
+            commands::list_locations,
+            commands::refresh_inventory,
+            commands::get_alert_feed,
+            window::open_dashboard_window,
+        ])
+        .setup(|app| {
+            let splashscreen = app
+                .get_webview_window("splashscreen")
+                .expect("splashscreen window");
+            let main_window = app.get_webview_window("main").expect("main window");
+            main_window.set_title("StorePulse")?;
+            // Restore the saved size/position/maximized state before the window
+            // is revealed so it comes up where the operator last left it. Exclude
+            // VISIBLE: the saved state has the main window shown from last run, and
+            // restoring it would reveal the window here in `setup`, before
+            // `warm_up` resolves — defeating the splashscreen-gated reveal.
+            #[cfg(desktop)]
+            let _ = main_window
+                .restore_state(StateFlags::SIZE | StateFlags::POSITION | StateFlags::MAXIMIZED);
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                match warm_up(handle).await {
+                    Ok(()) => {
+                        let _ = splashscreen.close();
+                        let _ = main_window.show();
+                    }
+                    Err(err) => {
+                        // Keep the splashscreen up and surface the failure
+                        // rather than revealing an empty main window.
+                        let _ = splashscreen.emit("warm-up-error", err);
+                    }
+                }
+            });
+
+            Ok(())
+        })
+        .run(tauri::generate_context!())
+        .expect("failed to run StorePulse");
+}
@@ -0,0 +1,107 @@
+//! Spawning of detached dashboard and per-location detail windows.
+//!
+//! Operators can pop a live dashboard or a location detail view out into its
+//! own OS window. Windows are tracked in a [`WindowRegistry`] keyed by label so
+//! that requesting an already-open window focuses it instead of creating a
+//! duplicate.
+//!
+//! Creation is dispatched onto the main loop via [`AppHandle::run_on_main_thread`]
+//! rather than performed inline in the awaited command: building a window
+//! directly after a `get_webview_window` lookup can overflow the main thread's
+//! stack on Windows.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder, WindowEvent, Wry};
+
+use crate::commands::{CommandError, CommandResult};
+
+/// Labels of the detached windows this app has opened.
+#[derive(Default)]
+pub struct WindowRegistry(pub Mutex<HashSet<String>>);
+
+/// Default inner size for a popped-out window, in logical pixels.
+const DEFAULT_SIZE: (f64, f64) = (1280.0, 800.0);
+
+/// Open (or focus) a detached dashboard/detail window.
+///
+/// If a window with `label` already exists it is un-minimized and focused;
+/// otherwise a new window is built on the main loop pointing at `route`.
+#[tauri::command]
+pub async fn open_dashboard_window(
+    app: AppHandle<Wry>,
+    label: String,
+    route: String,
+) -> CommandResult<()> {
+    if let Some(existing) = app.get_webview_window(&label) {
+        let _ = existing.unminimize();
+        existing
+            .set_focus()
+            .map_err(|e| CommandError::DataSource(e.to_string()))?;
+        return Ok(());
+    }
+
+    // The registry is the authoritative record of which detached windows are
+    // live. If the label is already tracked the window exists or is being built
+    // (the `WindowEvent::Destroyed` handler below prunes it otherwise), so there is nothing to build.
+    {
+        let registry = app.state::<WindowRegistry>();
+        let mut labels = registry.0.lock().unwrap();
+        if !labels.insert(label.clone()) {
+            return Ok(());
+        }
+    }
+
+    // Keep a handle and label for the dispatch-error path: if
+    // `run_on_main_thread` itself fails the closure never runs, so the registry
+    // entry inserted above must be dropped here or the label stays tracked and
+    // un-openable forever.
+    let dispatch_app = app.clone();
+    let dispatch_label = label.clone();
+
+    app.run_on_main_thread(move || {
+        let builder = WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(route.into()))
+            .inner_size(DEFAULT_SIZE.0, DEFAULT_SIZE.1)
+            .center();
+        match builder.build() {
+            Ok(window) => {
+                // Prune the label when the window closes so it can be reopened.
+                let handle = app.clone();
+                let tracked = label.clone();
+                window.on_window_event(move |event| {
+                    if matches!(event, WindowEvent::Destroyed) {
+                        handle
+                            .state::<WindowRegistry>()
+                            .0
+                            .lock()
+                            .unwrap()
+                            .remove(&tracked);
+                    }
+                });
+            }
+            Err(err) => {
+                // Drop the registry entry so a later attempt can retry, and
+                // surface the failure to the frontend rather than the console.
+                app.state::<WindowRegistry>()
+                    .0
+                    .lock()
+                    .unwrap()
+                    .remove(&label);
+                let _ = app.emit("window-open-error", format!("{label}: {err}"));
+            }
+        }
+    })
+    .map_err(|e| {
+        // The closure never ran, so undo the registry insert ourselves.
+        dispatch_app
+            .state::<WindowRegistry>()
+            .0
+            .lock()
+            .unwrap()
+            .remove(&dispatch_label);
+        CommandError::DataSource(e.to_string())
+    })?;
+
+    Ok(())
+}